@@ -1,4 +1,8 @@
-use rand::Rng;
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 #[cfg(test)]
 mod wish_tests {
@@ -22,20 +26,19 @@ mod wish_tests {
 
     #[test]
     fn weight_increase_test() {
-        let weight = Weights::new(0.006, 0.051);
-        let pity = Pity::new(73, 90, 9);
+        let weight = Weights::new(0.006, 0.051, LinearPity::new(73, 90), 9);
 
         let state = RegularState::new(74, 10);
-        let arr = weight.get_distribution(&pity, &state);
+        let arr = weight.get_distribution(&state);
         assert!(arr[0] > 0.006);
         assert!(arr[1] >= 1.);
 
         let state = RegularState::new(89, 9);
-        let arr = weight.get_distribution(&pity, &state);
+        let arr = weight.get_distribution(&state);
         assert!(arr[0] < 1.);
 
         let state = RegularState::new(90, 9);
-        let arr = weight.get_distribution(&pity, &state);
+        let arr = weight.get_distribution(&state);
         assert!(arr[0] >= 1.);
         assert_ne!(arr[1], 1.);
     }
@@ -49,11 +52,10 @@ mod wish_tests {
         let mut s4 = 0.;
 
         let wish = RegularWish {
-            weights: Weights::new(0.006, 0.051),
-            pity: Pity::new(73, 90, 9),
-            five_star_count: 100,
-            four_star_count: 100,
-            three_star_count: 100,
+            weights: Weights::new(0.006, 0.051, LinearPity::new(73, 90), 9),
+            five_star: ItemPool::uniform(100),
+            four_star: ItemPool::uniform(100),
+            three_star: ItemPool::uniform(100),
         };
 
         for _ in 0..ROLLS {
@@ -70,6 +72,241 @@ mod wish_tests {
         test_tol(s4, 0.13);
     }
 
+    #[test]
+    fn luck_tier_classifies_extremes() {
+        assert_eq!(LuckTier::classify(0.001), LuckTier::ExtremelyLucky);
+        assert_eq!(LuckTier::classify(0.05), LuckTier::Lucky);
+        assert_eq!(LuckTier::classify(0.5), LuckTier::Average);
+        assert_eq!(LuckTier::classify(0.95), LuckTier::Unlucky);
+        assert_eq!(LuckTier::classify(0.999), LuckTier::ExtremelyUnlucky);
+    }
+
+    #[test]
+    fn statistics_report_matches_observed_tier() {
+        let wish = FeaturedWish {
+            base: RegularWish {
+                weights: Weights::new(0.006, 0.051, LinearPity::new(73, 90), 9),
+                five_star: ItemPool::uniform(100),
+                four_star: ItemPool::uniform(100),
+                three_star: ItemPool::uniform(100),
+            },
+            five_star_featured: ItemPool::uniform(100),
+            four_star_featured: ItemPool::uniform(100),
+            featured_chance: 0.5,
+            secondary_guarantee: None,
+        };
+
+        let state = FeaturedState::new(RegularState::new(1, 1), true, true);
+        let mut rng = rand::thread_rng();
+        let summary = Statistics::report(&wish, state.clone(), 2_000, 200, 90, &mut rng);
+
+        assert!(!summary.s5_histogram.is_empty());
+        assert!(!summary.featured_histogram.is_empty());
+        assert!(summary.s5_report.mean > 0.);
+        assert!(summary.featured_report.p99 >= summary.featured_report.p90);
+        assert_eq!(
+            summary.observed_tier,
+            wish.classify_featured_luck(&state, 90)
+        );
+    }
+
+    #[test]
+    fn replay_with_same_seed_reproduces_history() {
+        let wish = RegularWish {
+            weights: Weights::new(0.006, 0.051, LinearPity::new(73, 90), 9),
+            five_star: ItemPool::uniform(100),
+            four_star: ItemPool::uniform(100),
+            three_star: ItemPool::uniform(100),
+        };
+
+        let first = replay(&wish, 42, RegularState::new(1, 1), 200);
+        let second = replay(&wish, 42, RegularState::new(1, 1), 200);
+
+        assert_eq!(first.seed, second.seed);
+        assert_eq!(first.pity_after_each_pull.len(), 200);
+        assert_eq!(first.final_state.since_s5, second.final_state.since_s5);
+        for (a, b) in first.rolls.iter().zip(second.rolls.iter()) {
+            assert_eq!(a.index, b.index);
+            assert_eq!(a.kind, b.kind);
+        }
+    }
+
+    #[test]
+    fn replay_with_different_seed_can_diverge() {
+        let wish = RegularWish {
+            weights: Weights::new(0.006, 0.051, LinearPity::new(73, 90), 9),
+            five_star: ItemPool::uniform(100),
+            four_star: ItemPool::uniform(100),
+            three_star: ItemPool::uniform(100),
+        };
+
+        let a = replay(&wish, 1, RegularState::new(1, 1), 500);
+        let b = replay(&wish, 2, RegularState::new(1, 1), 500);
+
+        assert!(a.rolls.iter().zip(b.rolls.iter()).any(|(x, y)| x.index != y.index));
+    }
+
+    #[test]
+    fn weighted_pool_favors_heavier_items() {
+        let pool = ItemPool::weighted(vec![1, 0, 0, 99]);
+        assert_eq!(pool.weights(), &[1, 0, 0, 99]);
+        let mut rng = rand::thread_rng();
+
+        let mut hits = 0.0_f64;
+        for _ in 0..ROLLS {
+            if pool.draw(&mut rng) == 3 {
+                hits += 1.;
+            }
+        }
+        test_tol(hits, 0.99);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one item")]
+    fn weighted_pool_rejects_empty_weights() {
+        ItemPool::weighted(vec![]);
+    }
+
+    #[test]
+    #[should_panic(expected = "sum to more than zero")]
+    fn weighted_pool_rejects_all_zero_weights() {
+        ItemPool::weighted(vec![0, 0, 0]);
+    }
+
+    #[test]
+    fn pull_multi_returns_n_rolls_and_final_state() {
+        let wish = RegularWish {
+            weights: Weights::new(0.006, 0.051, LinearPity::new(73, 90), 9),
+            five_star: ItemPool::uniform(100),
+            four_star: ItemPool::uniform(100),
+            three_star: ItemPool::uniform(100),
+        };
+
+        let mut rng = rand::thread_rng();
+        let (rolls, state) = wish.pull_multi(RegularState::new(1, 1), 50, &mut rng);
+
+        assert_eq!(rolls.len(), 50);
+        assert!(state.since_s5 >= 1 && state.since_s4 >= 1);
+    }
+
+    #[test]
+    fn session_is_a_plain_iterator() {
+        let wish = RegularWish {
+            weights: Weights::new(0.006, 0.051, LinearPity::new(73, 90), 9),
+            five_star: ItemPool::uniform(100),
+            four_star: ItemPool::uniform(100),
+            three_star: ItemPool::uniform(100),
+        };
+
+        let mut rng = rand::thread_rng();
+        let rolls: Vec<Roll> = wish.session(RegularState::new(1, 1), &mut rng).take(10).collect();
+        assert_eq!(rolls.len(), 10);
+    }
+
+    #[test]
+    fn five_star_pmf_sums_to_one() {
+        let wish = RegularWish {
+            weights: Weights::new(0.006, 0.051, LinearPity::new(73, 90), 9),
+            five_star: ItemPool::uniform(100),
+            four_star: ItemPool::uniform(100),
+            three_star: ItemPool::uniform(100),
+        };
+
+        let state = RegularState::new(1, 1);
+        let pmf = wish.five_star_pmf(&state);
+
+        // The chain is absorbing at the curve's hard pity, so the PMF is finite and must sum to 1.
+        let total: f64 = pmf.iter().sum();
+        assert!((total - 1.).abs() < 1e-9);
+
+        // Pulling exactly at hard pity is a certainty.
+        assert_eq!(
+            pmf.len() as u32,
+            wish.weights.s5_curve.end - state.since_s5 + 1
+        );
+    }
+
+    #[test]
+    fn five_star_cdf_matches_pmf() {
+        let wish = RegularWish {
+            weights: Weights::new(0.006, 0.051, LinearPity::new(73, 90), 9),
+            five_star: ItemPool::uniform(100),
+            four_star: ItemPool::uniform(100),
+            three_star: ItemPool::uniform(100),
+        };
+
+        let state = RegularState::new(89, 9);
+        let pmf = wish.five_star_pmf(&state);
+        let cdf = wish.five_star_cdf(&state);
+
+        assert!((cdf[0] - pmf[0]).abs() < 1e-9);
+        assert!((*cdf.last().unwrap() - 1.).abs() < 1e-9);
+        assert!((wish.probability_within(&state, 1) - pmf[0]).abs() < 1e-9);
+    }
+
+    #[test]
+    fn five_star_pmf_exact_matches_float() {
+        let wish = RegularWish {
+            weights: Weights::new(0.006, 0.051, LinearPity::new(73, 90), 9),
+            five_star: ItemPool::uniform(100),
+            four_star: ItemPool::uniform(100),
+            three_star: ItemPool::uniform(100),
+        };
+
+        let state = RegularState::new(80, 1);
+        let pmf = wish.five_star_pmf(&state);
+        let pmf_exact = wish.five_star_pmf_exact(&state, Rational::new(6, 1000));
+
+        assert_eq!(pmf.len(), pmf_exact.len());
+        for (f, r) in pmf.iter().zip(pmf_exact.iter()) {
+            assert!((f - r.to_f64()).abs() < 1e-9);
+        }
+
+        let total: Rational = pmf_exact
+            .into_iter()
+            .fold(Rational::new(0, 1), |acc, p| acc.add(p));
+        assert!((total.to_f64() - 1.).abs() < 1e-9);
+    }
+
+    #[test]
+    fn featured_pmf_respects_guarantee() {
+        let wish = FeaturedWish {
+            base: RegularWish {
+                weights: Weights::new(0.006, 0.051, LinearPity::new(73, 90), 9),
+                five_star: ItemPool::uniform(100),
+                four_star: ItemPool::uniform(100),
+                three_star: ItemPool::uniform(100),
+            },
+            five_star_featured: ItemPool::uniform(100),
+            four_star_featured: ItemPool::uniform(100),
+            featured_chance: 0.5,
+            secondary_guarantee: None,
+        };
+
+        // Guarantee already owed: the very next 5star is certainly featured.
+        let guaranteed = FeaturedState::new(RegularState::new(1, 1), false, true);
+        let s5_pmf = wish.base.five_star_pmf(&guaranteed.base);
+        let featured_pmf = wish.featured_pmf(&guaranteed);
+        for (s5, featured) in s5_pmf.iter().zip(featured_pmf.iter()) {
+            assert!((s5 - featured).abs() < 1e-9);
+        }
+
+        // No guarantee owed: only half of the 5star PMF mass lands as featured on the first hit.
+        let fresh = FeaturedState::new(RegularState::new(1, 1), true, true);
+        let featured_pmf = wish.featured_pmf(&fresh);
+        assert!((featured_pmf[0] - s5_pmf[0] * 0.5).abs() < 1e-9);
+        assert!(
+            wish.probability_featured_within(&fresh, 1) < wish.probability_featured_within(&fresh, 90)
+        );
+
+        // The featured chance is constant across the whole chain (not just the first pull), and
+        // the full distribution (first-hit-featured, plus lose-then-guaranteed-next) still sums
+        // to a certainty.
+        let total: f64 = featured_pmf.iter().sum();
+        assert!((total - 1.).abs() < 1e-9);
+        assert!(wish.probability_featured_within(&fresh, 90) < 0.99);
+    }
+
     #[test]
     fn featured_wish_test() {
         let mut state = FeaturedState::new(RegularState::new(1, 1), true, true);
@@ -80,15 +317,15 @@ mod wish_tests {
 
         let wish = FeaturedWish {
             base: RegularWish {
-                weights: Weights::new(0.006, 0.051),
-                pity: Pity::new(73, 90, 9),
-                five_star_count: 100,
-                four_star_count: 100,
-                three_star_count: 100,
+                weights: Weights::new(0.006, 0.051, LinearPity::new(73, 90), 9),
+                five_star: ItemPool::uniform(100),
+                four_star: ItemPool::uniform(100),
+                three_star: ItemPool::uniform(100),
             },
-            five_star_featured_count: 100,
-            four_star_featured_count: 100,
+            five_star_featured: ItemPool::uniform(100),
+            four_star_featured: ItemPool::uniform(100),
             featured_chance: 0.5,
+            secondary_guarantee: None,
         };
 
         for _ in 0..ROLLS {
@@ -106,12 +343,103 @@ mod wish_tests {
         test_tol(s5, 0.016);
         test_tol(s4, 0.13);
     }
+
+    #[test]
+    fn piecewise_pity_accumulates_segment_increments() {
+        let curve = PiecewisePity::new(vec![(70, 0.05), (85, 0.2)]);
+
+        assert_eq!(curve.rate(0.01, 70), 0.01);
+        assert!((curve.rate(0.01, 75) - 0.26).abs() < 1e-9);
+        assert_eq!(curve.rate(0.01, 100), 1.);
+    }
+
+    #[test]
+    fn secondary_guarantee_forces_featured_after_consecutive_losses() {
+        let wish = FeaturedWish {
+            base: RegularWish {
+                weights: Weights::new(0.006, 0.051, LinearPity::new(73, 90), 9),
+                five_star: ItemPool::uniform(100),
+                four_star: ItemPool::uniform(100),
+                three_star: ItemPool::uniform(100),
+            },
+            five_star_featured: ItemPool::uniform(100),
+            four_star_featured: ItemPool::uniform(100),
+            featured_chance: 0.,
+            secondary_guarantee: Some(2),
+        };
+
+        let state =
+            FeaturedState::with_consecutive_s5_losses(RegularState::new(1, 1), true, true, 2);
+        let mut rng = rand::thread_rng();
+        let (roll, next) = wish.make_s5_roll(state, &mut rng);
+
+        assert_eq!(roll.kind, RollKind::FiveStarFeatured);
+        assert!(next.last_s5_featured);
+        assert_eq!(next.consecutive_s5_losses, 0);
+    }
+
+    #[test]
+    fn secondary_guarantee_not_yet_owed_can_still_lose() {
+        let wish = FeaturedWish {
+            base: RegularWish {
+                weights: Weights::new(0.006, 0.051, LinearPity::new(73, 90), 9),
+                five_star: ItemPool::uniform(100),
+                four_star: ItemPool::uniform(100),
+                three_star: ItemPool::uniform(100),
+            },
+            five_star_featured: ItemPool::uniform(100),
+            four_star_featured: ItemPool::uniform(100),
+            featured_chance: 0.,
+            secondary_guarantee: Some(3),
+        };
+
+        let state =
+            FeaturedState::with_consecutive_s5_losses(RegularState::new(1, 1), true, true, 1);
+        let mut rng = rand::thread_rng();
+        let (roll, next) = wish.make_s5_roll(state, &mut rng);
+
+        assert_eq!(roll.kind, RollKind::FiveStar);
+        assert_eq!(next.consecutive_s5_losses, 2);
+    }
+
+    #[test]
+    fn featured_pmf_respects_secondary_guarantee() {
+        let wish = FeaturedWish {
+            base: RegularWish {
+                weights: Weights::new(0.006, 0.051, LinearPity::new(73, 90), 9),
+                five_star: ItemPool::uniform(100),
+                four_star: ItemPool::uniform(100),
+                three_star: ItemPool::uniform(100),
+            },
+            five_star_featured: ItemPool::uniform(100),
+            four_star_featured: ItemPool::uniform(100),
+            featured_chance: 0.5,
+            secondary_guarantee: Some(2),
+        };
+
+        // No ordinary guarantee owed, but the secondary counter has already reached the
+        // threshold: the next 5star must be treated as certainly featured, same as the base PMF.
+        let state =
+            FeaturedState::with_consecutive_s5_losses(RegularState::new(1, 1), true, true, 2);
+        let s5_pmf = wish.base.five_star_pmf(&state.base);
+        let featured_pmf = wish.featured_pmf(&state);
+        for (s5, featured) in s5_pmf.iter().zip(featured_pmf.iter()) {
+            assert!((s5 - featured).abs() < 1e-9);
+        }
+
+        // Below the threshold, the secondary counter doesn't yet force anything: it's the
+        // ordinary 50/50-with-pity-back-up distribution.
+        let below_threshold =
+            FeaturedState::with_consecutive_s5_losses(RegularState::new(1, 1), true, true, 0);
+        let featured_pmf = wish.featured_pmf(&below_threshold);
+        assert!((featured_pmf[0] - s5_pmf[0] * 0.5).abs() < 1e-9);
+    }
 }
 
 /* To calculate the pity of a regular wish we just needs how many rolls have been made
  * since the last drop of rarity affected by pity.
  */
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct RegularState {
     since_s5: u32,
     since_s4: u32,
@@ -126,11 +454,12 @@ impl RegularState {
 /* In addition to the logic behind the regular pity, featured wishes have a featured guarantee
  * that procs if the last high rarity of a category wasn't a featured item.
  */
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct FeaturedState {
     base: RegularState,
     last_s5_featured: bool,
     last_s4_featured: bool,
+    consecutive_s5_losses: u32,
 }
 
 impl FeaturedState {
@@ -139,86 +468,208 @@ impl FeaturedState {
             base,
             last_s5_featured,
             last_s4_featured,
+            consecutive_s5_losses: 0,
         }
     }
+
+    /* Same as `new`, but carrying over an existing secondary-guarantee counter instead of
+     * resetting it to zero.
+     */
+    fn with_consecutive_s5_losses(
+        base: RegularState,
+        last_s5_featured: bool,
+        last_s4_featured: bool,
+        consecutive_s5_losses: u32,
+    ) -> Self {
+        Self {
+            base,
+            last_s5_featured,
+            last_s4_featured,
+            consecutive_s5_losses,
+        }
+    }
+}
+
+/* Generalizes the shape of the 5star soft-pity ramp: given the base (no-pity) rate and how many
+ * pulls it's been since the last 5star, returns the rate that actually applies. A curve is
+ * expected to reach exactly `1.0` at its hard pity so the Markov-chain PMF engine below knows
+ * when the "no 5star yet" chain has absorbed.
+ */
+trait PityCurve {
+    fn rate(&self, base: f64, since: u32) -> f64;
 }
 
+/* The original single-ramp pity: a flat `base` rate until `start`, then a straight-line ramp up
+ * to a guaranteed 1.0 at `end`.
+ */
 #[derive(Debug, Clone)]
-struct Weights {
-    s5: f64,
-    s4: f64,
+struct LinearPity {
+    start: u32,
+    end: u32,
+}
+
+impl LinearPity {
+    fn new(start: u32, end: u32) -> Self {
+        Self { start, end }
+    }
+}
+
+impl PityCurve for LinearPity {
+    fn rate(&self, base: f64, since: u32) -> f64 {
+        if since <= self.start {
+            base
+        } else {
+            let inc = (1. - base) / (self.end - self.start) as f64;
+            (base + inc * (since - self.start) as f64).min(1.)
+        }
+    }
 }
 
+/* A multi-segment "soft pity" curve built from `(start_pull, per_pull_increment)` breakpoints:
+ * flat at `base` up to the first breakpoint, then the rate climbs by that breakpoint's increment
+ * per pull until the next breakpoint takes over (e.g. a gentle ramp followed by a much steeper
+ * one right before hard pity).
+ */
 #[derive(Debug, Clone)]
-struct Pity {
-    s5_start: u32,
-    s5_end: u32,
-    s4_proc: u32,
+struct PiecewisePity {
+    breakpoints: Vec<(u32, f64)>,
 }
 
-impl Pity {
-    fn new(s5_start: u32, s5_end: u32, s4_proc: u32) -> Self {
-        Self {
-            s5_start,
-            s5_end,
-            s4_proc,
+impl PiecewisePity {
+    fn new(mut breakpoints: Vec<(u32, f64)>) -> Self {
+        assert!(
+            !breakpoints.is_empty(),
+            "PiecewisePity needs at least one breakpoint"
+        );
+        breakpoints.sort_by_key(|&(start, _)| start);
+        assert!(
+            breakpoints.last().is_some_and(|&(_, inc)| inc > 0.),
+            "PiecewisePity's last breakpoint must have a positive increment, or the rate never reaches hard pity"
+        );
+        Self { breakpoints }
+    }
+}
+
+impl PityCurve for PiecewisePity {
+    fn rate(&self, base: f64, since: u32) -> f64 {
+        let mut rate = base;
+
+        for (i, &(start, inc)) in self.breakpoints.iter().enumerate() {
+            if since <= start {
+                break;
+            }
+
+            let next_start = self.breakpoints.get(i + 1).map_or(since, |&(s, _)| s);
+            let steps = since.min(next_start) - start;
+            rate += inc * steps as f64;
         }
+
+        rate.min(1.)
     }
 }
 
-impl Weights {
-    fn new(s5: f64, s4: f64) -> Self {
-        Self { s5, s4 }
+#[derive(Debug, Clone)]
+struct Weights<C: PityCurve> {
+    s5: f64,
+    s4: f64,
+    s5_curve: C,
+    s4_proc: u32,
+}
+
+impl<C: PityCurve> Weights<C> {
+    fn new(s5: f64, s4: f64, s5_curve: C, s4_proc: u32) -> Self {
+        Self {
+            s5,
+            s4,
+            s5_curve,
+            s4_proc,
+        }
     }
 
-    /* Given a pity and a state, get_distribution will calculate the corresponing weights,
+    /* Given a state, get_distribution will calculate the corresponing weights,
      * i.e.: The real odds after taking into account pity of getting 5star, 4star or 3star items.
      * The array of odds has a size of two, since the odds of getting a 3star items is (1 - 4star_odds - 5star_odds).
      */
-    fn get_distribution(&self, pity: &Pity, state: &RegularState) -> [f64; 2] {
-        let s5_odds = if state.since_s5 <= pity.s5_start {
-            self.s5
-        } else {
-            let inc = (1. - self.s5) / (pity.s5_end - pity.s5_start) as f64;
-            self.s5 + inc * (state.since_s5 - pity.s5_start) as f64
-        };
+    fn get_distribution(&self, state: &RegularState) -> [f64; 2] {
+        let s5_odds = self.s5_curve.rate(self.s5, state.since_s5);
 
-        let s4_odds = if state.since_s4 < pity.s4_proc {
+        let s4_odds = if state.since_s4 < self.s4_proc {
             self.s4
         } else {
             let inc = (1. - self.s4) / 2.;
-            self.s4 + inc * (state.since_s4 - pity.s4_proc + 1) as f64
+            self.s4 + inc * (state.since_s4 - self.s4_proc + 1) as f64
         };
 
         [s5_odds, s4_odds + s5_odds]
     }
 }
 
+/* A weighted pool of items within a single rarity bucket. Build one with `ItemPool::uniform(n)`
+ * for the old "every item is equally likely" behavior, or `ItemPool::weighted(weights)` to give
+ * individual items (e.g. a rate-up character among the featured set) a higher chance of winning.
+ * `Roll.index` is always an index into the pool the roll was drawn from.
+ */
+#[derive(Debug, Clone)]
+struct ItemPool {
+    weights: Vec<u32>,
+    dist: WeightedIndex<u32>,
+}
+
+impl ItemPool {
+    /* Every item equally likely, matching the old `gen_range(0..count)` behavior. */
+    fn uniform(count: u32) -> Self {
+        Self::weighted(vec![1; count as usize])
+    }
+
+    /* One entry per item, each entry being that item's ticket count; the winner is drawn
+     * proportionally to its weight.
+     */
+    fn weighted(weights: Vec<u32>) -> Self {
+        assert!(!weights.is_empty(), "ItemPool needs at least one item");
+        assert!(
+            weights.iter().sum::<u32>() > 0,
+            "ItemPool weights must sum to more than zero"
+        );
+
+        let dist = WeightedIndex::new(weights.iter().copied()).expect("invalid ItemPool weights");
+        Self { weights, dist }
+    }
+
+    fn draw<R: Rng + ?Sized>(&self, rng: &mut R) -> u32 {
+        self.dist.sample(rng) as u32
+    }
+
+    fn weights(&self) -> &[u32] {
+        &self.weights
+    }
+}
+
 /* A wish is nothing more than the weight (odds of dropping) of the different rarities,
  * the pity (how does odds change depending on the amount of rolls),
  * and the data related to the contents of the pool the wish is related to (amount of items)
  */
 #[derive(Debug, Clone)]
-struct RegularWish {
-    weights: Weights,
-    pity: Pity,
-    five_star_count: u32,
-    four_star_count: u32,
-    three_star_count: u32,
+struct RegularWish<C: PityCurve> {
+    weights: Weights<C>,
+    five_star: ItemPool,
+    four_star: ItemPool,
+    three_star: ItemPool,
 }
 
 /* The featured wish is the same, but it has some new data related to the pool (amount of featured items),
- * and the odds of those items.
+ * and the odds of those items. `secondary_guarantee`, when set, is the number of consecutive lost
+ * 50/50s ("capturing radiance") after which the next 5star is forced to be featured.
  */
 #[derive(Debug, Clone)]
-struct FeaturedWish {
-    base: RegularWish,
-    five_star_featured_count: u32,
-    four_star_featured_count: u32,
+struct FeaturedWish<C: PityCurve> {
+    base: RegularWish<C>,
+    five_star_featured: ItemPool,
+    four_star_featured: ItemPool,
     featured_chance: f64,
+    secondary_guarantee: Option<u32>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 enum RollKind {
     FiveStar,
     FiveStarFeatured,
@@ -236,7 +687,7 @@ enum RollKind {
  * Roll { kind: RollKind::FiveStar, index: 1 }
  * would represent "madcat", that is, the FiveStar item in the index 1 relative to the pool.
  */
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct Roll {
     kind: RollKind,
     index: u32,
@@ -250,37 +701,37 @@ impl Roll {
 
 /* See FeaturedWish
  */
-impl RegularWish {
+impl<C: PityCurve> RegularWish<C> {
     /* Create the roll, get a random index from the pool, and increase both pity counts.
      */
-    fn make_s3_roll<R: Rng>(&self, state: RegularState, rng: &mut R) -> (Roll, RegularState) {
+    fn make_s3_roll<R: Rng + ?Sized>(&self, state: RegularState, rng: &mut R) -> (Roll, RegularState) {
         (
-            Roll::new(RollKind::ThreeStar, rng.gen_range(0..self.three_star_count)),
+            Roll::new(RollKind::ThreeStar, self.three_star.draw(rng)),
             RegularState::new(state.since_s5 + 1, state.since_s4 + 1),
         )
     }
 
     /* Create the roll, get a random index from the pool, and increase 5 star pity count.
      */
-    fn make_s4_roll<R: Rng>(&self, state: RegularState, rng: &mut R) -> (Roll, RegularState) {
+    fn make_s4_roll<R: Rng + ?Sized>(&self, state: RegularState, rng: &mut R) -> (Roll, RegularState) {
         (
-            Roll::new(RollKind::FourStar, rng.gen_range(0..self.four_star_count)),
+            Roll::new(RollKind::FourStar, self.four_star.draw(rng)),
             RegularState::new(state.since_s5 + 1, 1),
         )
     }
 
     /* Create the roll, get a random index from the pool, and increase 4 star pity count.
      */
-    fn make_s5_roll<R: Rng>(&self, state: RegularState, rng: &mut R) -> (Roll, RegularState) {
+    fn make_s5_roll<R: Rng + ?Sized>(&self, state: RegularState, rng: &mut R) -> (Roll, RegularState) {
         (
-            Roll::new(RollKind::FiveStar, rng.gen_range(0..self.five_star_count)),
+            Roll::new(RollKind::FiveStar, self.five_star.draw(rng)),
             RegularState::new(1, state.since_s4 + 1),
         )
     }
 
-    fn roll<R: Rng>(&self, state: RegularState, rng: &mut R) -> (Roll, RegularState) {
+    fn roll<R: Rng + ?Sized>(&self, state: RegularState, rng: &mut R) -> (Roll, RegularState) {
         let roll: f64 = rng.gen();
-        let dist = self.weights.get_distribution(&self.pity, &state);
+        let dist = self.weights.get_distribution(&state);
         if roll < dist[0] {
             self.make_s5_roll(state, rng)
         } else if roll < dist[1] {
@@ -291,12 +742,17 @@ impl RegularWish {
     }
 }
 
-impl FeaturedWish {
-    fn make_s3_roll<R: Rng>(&self, state: FeaturedState, rng: &mut R) -> (Roll, FeaturedState) {
+impl<C: PityCurve> FeaturedWish<C> {
+    fn make_s3_roll<R: Rng + ?Sized>(&self, state: FeaturedState, rng: &mut R) -> (Roll, FeaturedState) {
         let (roll, base) = self.base.make_s3_roll(state.base, rng);
         (
             roll,
-            FeaturedState::new(base, state.last_s5_featured, state.last_s4_featured),
+            FeaturedState::with_consecutive_s5_losses(
+                base,
+                state.last_s5_featured,
+                state.last_s4_featured,
+                state.consecutive_s5_losses,
+            ),
         )
     }
 
@@ -304,58 +760,73 @@ impl FeaturedWish {
      * However we also check if the last item was featured, and if not, another check for the featured chance.
      * Oh, we also update the pity state accordingly.
      */
-    fn make_s4_roll<R: Rng>(&self, state: FeaturedState, rng: &mut R) -> (Roll, FeaturedState) {
+    fn make_s4_roll<R: Rng + ?Sized>(&self, state: FeaturedState, rng: &mut R) -> (Roll, FeaturedState) {
         if !state.last_s4_featured || rng.gen_bool(self.featured_chance) {
             (
                 Roll::new(
                     RollKind::FourStarFeatured,
-                    rng.gen_range(0..self.four_star_featured_count),
+                    self.four_star_featured.draw(rng),
                 ),
-                FeaturedState::new(
+                FeaturedState::with_consecutive_s5_losses(
                     RegularState::new(state.base.since_s5, 1),
                     state.last_s5_featured,
                     true,
+                    state.consecutive_s5_losses,
                 ),
             )
         } else {
             let (roll, base) = self.base.make_s4_roll(state.base, rng);
             (
                 roll,
-                FeaturedState::new(base, state.last_s5_featured, false),
+                FeaturedState::with_consecutive_s5_losses(
+                    base,
+                    state.last_s5_featured,
+                    false,
+                    state.consecutive_s5_losses,
+                ),
             )
         }
     }
 
-    /* See the s4 version.
+    /* See the s4 version. The secondary guarantee ("capturing radiance") forces a featured win
+     * once `consecutive_s5_losses` reaches the configured threshold, on top of the existing
+     * guarantee/50-50 check.
      */
-    fn make_s5_roll<R: Rng>(&self, state: FeaturedState, rng: &mut R) -> (Roll, FeaturedState) {
-        if !state.last_s4_featured || rng.gen_bool(self.featured_chance) {
+    fn make_s5_roll<R: Rng + ?Sized>(&self, state: FeaturedState, rng: &mut R) -> (Roll, FeaturedState) {
+        let secondary_guarantee = self
+            .secondary_guarantee
+            .is_some_and(|threshold| state.consecutive_s5_losses >= threshold);
+
+        if !state.last_s5_featured || secondary_guarantee || rng.gen_bool(self.featured_chance) {
             (
                 Roll::new(
                     RollKind::FiveStarFeatured,
-                    rng.gen_range(0..self.five_star_featured_count),
+                    self.five_star_featured.draw(rng),
                 ),
-                FeaturedState::new(
+                FeaturedState::with_consecutive_s5_losses(
                     RegularState::new(1, state.base.since_s4),
                     true,
                     state.last_s4_featured,
+                    0,
                 ),
             )
         } else {
             let (roll, base) = self.base.make_s5_roll(state.base, rng);
             (
                 roll,
-                FeaturedState::new(base, false, state.last_s4_featured),
+                FeaturedState::with_consecutive_s5_losses(
+                    base,
+                    false,
+                    state.last_s4_featured,
+                    state.consecutive_s5_losses + 1,
+                ),
             )
         }
     }
 
-    fn roll<R: Rng>(&self, state: FeaturedState, rng: &mut R) -> (Roll, FeaturedState) {
+    fn roll<R: Rng + ?Sized>(&self, state: FeaturedState, rng: &mut R) -> (Roll, FeaturedState) {
         let roll: f64 = rng.gen();
-        let dist = self
-            .base
-            .weights
-            .get_distribution(&self.base.pity, &state.base);
+        let dist = self.base.weights.get_distribution(&state.base);
         if roll < dist[0] {
             self.make_s5_roll(state, rng)
         } else if roll < dist[1] {
@@ -365,3 +836,603 @@ impl FeaturedWish {
         }
     }
 }
+
+/* Common interface over `RegularWish`/`FeaturedWish` so `WishSession` can drive either one
+ * without duplicating the pull loop.
+ */
+trait Wish {
+    type State: Clone;
+
+    fn roll<R: Rng + ?Sized>(&self, state: Self::State, rng: &mut R) -> (Roll, Self::State);
+}
+
+impl<C: PityCurve> Wish for RegularWish<C> {
+    type State = RegularState;
+
+    fn roll<R: Rng + ?Sized>(&self, state: RegularState, rng: &mut R) -> (Roll, RegularState) {
+        RegularWish::roll(self, state, rng)
+    }
+}
+
+impl<C: PityCurve> Wish for FeaturedWish<C> {
+    type State = FeaturedState;
+
+    fn roll<R: Rng + ?Sized>(&self, state: FeaturedState, rng: &mut R) -> (Roll, FeaturedState) {
+        FeaturedWish::roll(self, state, rng)
+    }
+}
+
+/* Owns the evolving pity state across a run of pulls so callers don't have to manually thread
+ * the `(Roll, State)` tuple returned by `roll` through a loop: `wish.session(state, &mut
+ * rng).take(10).collect()` pulls ten times and stops there. Mirrors the stateful half of rand's
+ * distribution split, where `Distribution` below is the stateless, independent-sample side.
+ */
+struct WishSession<'a, W: Wish, R: Rng> {
+    wish: &'a W,
+    state: W::State,
+    rng: &'a mut R,
+}
+
+impl<'a, W: Wish, R: Rng> Iterator for WishSession<'a, W, R> {
+    type Item = Roll;
+
+    fn next(&mut self) -> Option<Roll> {
+        let (roll, state) = self.wish.roll(self.state.clone(), self.rng);
+        self.state = state;
+        Some(roll)
+    }
+}
+
+/* Stateless sampling hook so `RegularWish`/`FeaturedWish` plug into rand's `Distribution` API
+ * (e.g. `rng.sample_iter(wish)`). `Distribution::sample` has no way to thread state between
+ * calls, so each sample draws one independent pull from a fresh pity state (`since_s5`/`since_s4`
+ * both 1); use `WishSession` instead when pity needs to carry across pulls.
+ */
+impl<C: PityCurve> Distribution<(Roll, RegularState)> for RegularWish<C> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> (Roll, RegularState) {
+        self.roll(RegularState::new(1, 1), rng)
+    }
+}
+
+impl<C: PityCurve> Distribution<(Roll, FeaturedState)> for FeaturedWish<C> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> (Roll, FeaturedState) {
+        self.roll(FeaturedState::new(RegularState::new(1, 1), true, true), rng)
+    }
+}
+
+impl<C: PityCurve> RegularWish<C> {
+    /* Start a stateful pull session from `state`, pulling from `rng` on every `next()`. */
+    fn session<'a, R: Rng>(&'a self, state: RegularState, rng: &'a mut R) -> WishSession<'a, Self, R> {
+        WishSession {
+            wish: self,
+            state,
+            rng,
+        }
+    }
+
+    /* Pull `n` times in a row, returning the rolls in order alongside the resulting state. */
+    fn pull_multi<R: Rng>(&self, state: RegularState, n: u32, rng: &mut R) -> (Vec<Roll>, RegularState) {
+        let mut session = self.session(state, rng);
+        let rolls = (&mut session).take(n as usize).collect();
+        (rolls, session.state)
+    }
+}
+
+impl<C: PityCurve> FeaturedWish<C> {
+    /* Start a stateful pull session from `state`, pulling from `rng` on every `next()`. */
+    fn session<'a, R: Rng>(&'a self, state: FeaturedState, rng: &'a mut R) -> WishSession<'a, Self, R> {
+        WishSession {
+            wish: self,
+            state,
+            rng,
+        }
+    }
+
+    /* Pull `n` times in a row, returning the rolls in order alongside the resulting state. */
+    fn pull_multi<R: Rng>(&self, state: FeaturedState, n: u32, rng: &mut R) -> (Vec<Roll>, FeaturedState) {
+        let mut session = self.session(state, rng);
+        let rolls = (&mut session).take(n as usize).collect();
+        (rolls, session.state)
+    }
+}
+
+/* A fully reproducible record of one simulation run: every roll made, the pity state left behind
+ * by each one, and the final state, alongside the seed that produced it. `seed` and `PullHistory`
+ * both (de)serialize, so a user reporting "bad luck" can file the seed and have the exact pull
+ * sequence regenerated.
+ */
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PullHistory<S> {
+    seed: u64,
+    rolls: Vec<Roll>,
+    pity_after_each_pull: Vec<S>,
+    final_state: S,
+}
+
+/* Threads a seeded `StdRng` through a fixed number of pulls on a `Wish`, the way game simulators
+ * thread a `SeedableRng` through a whole run so it can be replayed pull-for-pull later.
+ */
+struct SimRun<'a, W: Wish> {
+    wish: &'a W,
+    seed: u64,
+    initial_state: W::State,
+    pulls: u32,
+}
+
+impl<'a, W: Wish> SimRun<'a, W> {
+    fn new(wish: &'a W, seed: u64, initial_state: W::State, pulls: u32) -> Self {
+        Self {
+            wish,
+            seed,
+            initial_state,
+            pulls,
+        }
+    }
+
+    fn run(&self) -> PullHistory<W::State> {
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let mut state = self.initial_state.clone();
+        let mut rolls = Vec::with_capacity(self.pulls as usize);
+        let mut pity_after_each_pull = Vec::with_capacity(self.pulls as usize);
+
+        for _ in 0..self.pulls {
+            let (roll, next_state) = self.wish.roll(state, &mut rng);
+            state = next_state;
+            rolls.push(roll);
+            pity_after_each_pull.push(state.clone());
+        }
+
+        PullHistory {
+            seed: self.seed,
+            rolls,
+            pity_after_each_pull,
+            final_state: state,
+        }
+    }
+}
+
+/* Entry point for regression tests: replays the exact same pull sequence for a given seed and
+ * config instead of looping over `thread_rng`, so a failing test reproduces deterministically.
+ */
+fn replay<W: Wish>(wish: &W, seed: u64, initial_state: W::State, pulls: u32) -> PullHistory<W::State> {
+    SimRun::new(wish, seed, initial_state, pulls).run()
+}
+
+/* Exact (non-stochastic) probability engine for pity-gated drops.
+ *
+ * `since_s5` only ever goes up by one per pull and resets to 1 on a 5star, and the rate it maps
+ * to is clamped to 1 at the pity curve's hard pity, so "no 5star yet" forms a finite absorbing Markov chain: each
+ * pull either absorbs (5star lands) or advances the counter by one. That means the full
+ * distribution of "pulls until the next 5star" can be read off directly, without sampling.
+ */
+impl<C: PityCurve> RegularWish<C> {
+    /* The 5star rate that applies once `since_s5` reaches `c`, clamped to 1 at hard pity. */
+    fn s5_rate_at(&self, c: u32) -> f64 {
+        let probe = RegularState::new(c, 0);
+        self.weights.get_distribution(&probe)[0].min(1.)
+    }
+
+    /* Exact probability mass function of "pulls until the next 5star" starting from `state`.
+     * `pmf[n]` is `P(next 5star on pull n + 1) = p_{c+n} * prod_{i=0}^{n-1}(1 - p_{c+i})`, where
+     * `c` is `state.since_s5`. The vector ends the pull at which `p` reaches 1, since the chain
+     * is guaranteed to have absorbed by then.
+     *
+     * `PityCurve` is documented to reach exactly 1.0 at some finite `since`, but nothing stops a
+     * caller from handing in a curve that doesn't (e.g. a `PiecewisePity` whose last breakpoint
+     * somehow has a zero increment); `MAX_PULLS_WITHOUT_HARD_PITY` bounds the search so a
+     * misconfigured curve panics instead of looping forever and growing `pmf` without bound.
+     */
+    fn five_star_pmf(&self, state: &RegularState) -> Vec<f64> {
+        const MAX_PULLS_WITHOUT_HARD_PITY: u32 = 100_000;
+
+        let mut pmf = Vec::new();
+        let mut survival = 1.;
+        let mut c = state.since_s5;
+        loop {
+            let p = self.s5_rate_at(c);
+            pmf.push(survival * p);
+            if p >= 1. {
+                return pmf;
+            }
+            assert!(
+                c - state.since_s5 < MAX_PULLS_WITHOUT_HARD_PITY,
+                "PityCurve never reached hard pity (rate < 1.0 after {MAX_PULLS_WITHOUT_HARD_PITY} pulls)"
+            );
+            survival *= 1. - p;
+            c += 1;
+        }
+    }
+
+    /* Cumulative distribution built from `five_star_pmf`: `cdf[n]` is the probability the 5star
+     * lands within the first `n + 1` pulls.
+     */
+    fn five_star_cdf(&self, state: &RegularState) -> Vec<f64> {
+        let mut running = 0.;
+        self.five_star_pmf(state)
+            .into_iter()
+            .map(|p| {
+                running += p;
+                running
+            })
+            .collect()
+    }
+
+    /* Expected number of pulls until the next 5star: E[X] = sum_n n * P(X = n). */
+    fn expected_pulls(&self, state: &RegularState) -> f64 {
+        self.five_star_pmf(state)
+            .iter()
+            .enumerate()
+            .map(|(i, p)| (i + 1) as f64 * p)
+            .sum()
+    }
+
+    /* Probability of landing the 5star within `budget` pulls or fewer. */
+    fn probability_within(&self, state: &RegularState, budget: u32) -> f64 {
+        match budget {
+            0 => 0.,
+            n => self
+                .five_star_cdf(state)
+                .get(n as usize - 1)
+                .copied()
+                .unwrap_or(1.),
+        }
+    }
+}
+
+impl<C: PityCurve> FeaturedWish<C> {
+    /* Exact PMF of "pulls until the next 5star, and it's the featured one", starting from
+     * `state`. If the guarantee is already owed -- either the ordinary one (`!last_s5_featured`)
+     * or the secondary "capturing radiance" one (`consecutive_s5_losses` has reached the
+     * configured threshold) -- the very next 5star is certainly featured, so the distribution is
+     * just the base 5star PMF. Otherwise every 5star in `first` (the base PMF for the *first*
+     * 5star) resolves featured with the same constant `featured_chance`; on a loss, the ordinary
+     * guarantee flips on for the 5star right after it regardless of the secondary counter, so the
+     * tail mass is the convolution of `first`'s losing branch with a fresh, now-guaranteed 5star
+     * PMF starting over at `since_s5 = 1`.
+     */
+    fn featured_pmf(&self, state: &FeaturedState) -> Vec<f64> {
+        let first = self.base.five_star_pmf(&state.base);
+
+        let guaranteed = !state.last_s5_featured
+            || self
+                .secondary_guarantee
+                .is_some_and(|threshold| state.consecutive_s5_losses >= threshold);
+
+        if guaranteed {
+            return first;
+        }
+
+        let chance = self.featured_chance;
+        let fresh = self.base.five_star_pmf(&RegularState::new(1, 0));
+
+        let mut pmf = vec![0.; first.len() + fresh.len()];
+        for (i, &p) in first.iter().enumerate() {
+            pmf[i] += p * chance;
+            for (j, &q) in fresh.iter().enumerate() {
+                pmf[i + j + 1] += p * (1. - chance) * q;
+            }
+        }
+        pmf
+    }
+
+    /* Probability the next featured 5star lands within `budget` pulls or fewer. */
+    fn probability_featured_within(&self, state: &FeaturedState, budget: u32) -> f64 {
+        if budget == 0 {
+            return 0.;
+        }
+        self.featured_pmf(state)
+            .iter()
+            .take(budget as usize)
+            .sum()
+    }
+}
+
+fn gcd(a: i128, b: i128) -> i128 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/* Exact-rational backend for the probability engine above. `f64` rounding is fine for "what are
+ * my odds", but a bug report built on a seed + exact config deserves an answer that doesn't
+ * depend on float rounding, so this mirrors `five_star_pmf` one-for-one using fractions.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Rational {
+    num: i128,
+    den: i128,
+}
+
+impl Rational {
+    fn new(num: i128, den: i128) -> Self {
+        assert!(den != 0, "Rational denominator must not be zero");
+        let sign = if den < 0 { -1 } else { 1 };
+        let g = gcd(num.abs(), den.abs()).max(1);
+        Self {
+            num: sign * num / g,
+            den: sign * den / g,
+        }
+    }
+
+    fn one() -> Self {
+        Self::new(1, 1)
+    }
+
+    /* Combine over the LCM of the two denominators rather than their raw product, so a chain of
+     * adds over an already-reduced fraction doesn't blow past `i128::MAX` the way repeatedly
+     * multiplying denominators together would for realistic pity spans.
+     */
+    fn add(self, other: Self) -> Self {
+        let g = gcd(self.den, other.den).max(1);
+        let lcm = self.den / g * other.den;
+        Self::new(self.num * (lcm / self.den) + other.num * (lcm / other.den), lcm)
+    }
+
+    fn sub(self, other: Self) -> Self {
+        let g = gcd(self.den, other.den).max(1);
+        let lcm = self.den / g * other.den;
+        Self::new(self.num * (lcm / self.den) - other.num * (lcm / other.den), lcm)
+    }
+
+    /* Cross-cancel common factors between each numerator and the *other* denominator before
+     * multiplying, for the same overflow reason as `add`/`sub`.
+     */
+    fn mul(self, other: Self) -> Self {
+        let g1 = gcd(self.num.abs(), other.den).max(1);
+        let g2 = gcd(other.num.abs(), self.den).max(1);
+        Self::new((self.num / g1) * (other.num / g2), (self.den / g2) * (other.den / g1))
+    }
+
+    fn div(self, other: Self) -> Self {
+        self.mul(Self::new(other.den, other.num))
+    }
+
+    fn min_one(self) -> Self {
+        if self.num >= self.den {
+            Self::one()
+        } else {
+            self
+        }
+    }
+
+    fn to_f64(self) -> f64 {
+        self.num as f64 / self.den as f64
+    }
+}
+
+/* The exact-rational backend only makes sense for `LinearPity`: its ramp is the only curve shape
+ * with a closed-form fraction, since `PiecewisePity`'s shape is caller-defined and not generally
+ * reducible to a single rational expression.
+ */
+impl RegularWish<LinearPity> {
+    /* Same ramp as `s5_rate_at`, computed with exact rational arithmetic. `Weights::s5` is an
+     * `f64` and so is already lossy, hence the caller supplies the true base rate as a fraction
+     * (e.g. `Rational::new(6, 1000)` for a 0.6% rate) rather than converting it back from float.
+     */
+    fn s5_rate_at_exact(&self, c: u32, s5_rate: Rational) -> Rational {
+        let curve = &self.weights.s5_curve;
+        if c <= curve.start {
+            return s5_rate;
+        }
+
+        let span = Rational::new((curve.end - curve.start) as i128, 1);
+        let inc = Rational::one().sub(s5_rate).div(span);
+        let steps = Rational::new((c - curve.start) as i128, 1);
+        s5_rate.add(inc.mul(steps)).min_one()
+    }
+
+    /* Exact-rational counterpart to `five_star_pmf`, reproducible to the last digit since it
+     * never touches `f64`.
+     */
+    fn five_star_pmf_exact(&self, state: &RegularState, s5_rate: Rational) -> Vec<Rational> {
+        let mut pmf = Vec::new();
+        let mut survival = Rational::one();
+        let mut c = state.since_s5;
+        loop {
+            let p = self.s5_rate_at_exact(c, s5_rate);
+            pmf.push(survival.mul(p));
+            if p == Rational::one() {
+                return pmf;
+            }
+            survival = survival.mul(Rational::one().sub(p));
+            c += 1;
+        }
+    }
+}
+
+impl<C: PityCurve> RegularWish<C> {
+    /* Classify an observed "landed the 5star in `pulls` pulls" result against the true analytic
+     * distribution from `probability_within`, rather than eyeballing it against a simulated mean.
+     */
+    fn classify_s5_luck(&self, state: &RegularState, pulls: u32) -> LuckTier {
+        LuckTier::classify(self.probability_within(state, pulls))
+    }
+}
+
+impl<C: PityCurve> FeaturedWish<C> {
+    /* Classify an observed "landed the featured 5star in `pulls` pulls" result against the true
+     * analytic distribution from `probability_featured_within`.
+     */
+    fn classify_featured_luck(&self, state: &FeaturedState, pulls: u32) -> LuckTier {
+        LuckTier::classify(self.probability_featured_within(state, pulls))
+    }
+}
+
+/* Luck tiers, adapting the "degrees of success" idea from tabletop dice rollers (normal / hard /
+ * extreme) to gacha outcomes: how an observed result ranks against the true distribution rather
+ * than just "good" or "bad".
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LuckTier {
+    ExtremelyLucky,
+    Lucky,
+    Average,
+    Unlucky,
+    ExtremelyUnlucky,
+}
+
+impl LuckTier {
+    /* `percentile` is the probability (read off the analytic CDF) that the observed result would
+     * land within that many pulls or fewer, i.e. how rare it is to do at least this well.
+     */
+    fn classify(percentile: f64) -> Self {
+        if percentile <= 0.01 {
+            LuckTier::ExtremelyLucky
+        } else if percentile <= 0.10 {
+            LuckTier::Lucky
+        } else if percentile <= 0.90 {
+            LuckTier::Average
+        } else if percentile <= 0.99 {
+            LuckTier::Unlucky
+        } else {
+            LuckTier::ExtremelyUnlucky
+        }
+    }
+}
+
+/* Mean/median/90th-/99th-percentile summary of a batch of "pulls until X" samples. */
+#[derive(Debug, Clone)]
+struct LuckReport {
+    mean: f64,
+    median: u32,
+    p90: u32,
+    p99: u32,
+}
+
+impl LuckReport {
+    /* `samples` is empty whenever `max_pulls` is too low for the event being measured to ever
+     * occur in a trial (e.g. no featured 5star within the budget), so that case reports all zeros
+     * rather than dividing by, or indexing into, nothing.
+     */
+    fn from_samples(samples: &[u32]) -> Self {
+        if samples.is_empty() {
+            return Self {
+                mean: 0.,
+                median: 0,
+                p90: 0,
+                p99: 0,
+            };
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let mean = sorted.iter().sum::<u32>() as f64 / sorted.len() as f64;
+        Self {
+            mean,
+            median: percentile_of_sorted(&sorted, 0.5),
+            p90: percentile_of_sorted(&sorted, 0.9),
+            p99: percentile_of_sorted(&sorted, 0.99),
+        }
+    }
+}
+
+/* Percentile of an already-sorted, non-empty slice: the element at index `p * (len - 1)`,
+ * rounded to the nearest index.
+ */
+fn percentile_of_sorted(sorted: &[u32], p: f64) -> u32 {
+    let rank = (p * (sorted.len() - 1) as f64).round() as usize;
+    sorted[rank.min(sorted.len() - 1)]
+}
+
+/* Full luck report for a `FeaturedWish`: the empirical histograms and percentile summaries built
+ * from simulated trials, plus the tier a concrete observed run classifies into against the true
+ * analytic distribution.
+ */
+#[derive(Debug, Clone)]
+struct LuckSummary {
+    s5_histogram: BTreeMap<u32, u32>,
+    s5_report: LuckReport,
+    featured_histogram: BTreeMap<u32, u32>,
+    featured_report: LuckReport,
+    observed_tier: LuckTier,
+}
+
+/* Aggregates many independent simulated sessions of a wish, recording how many pulls it took to
+ * land the first 5star and the first featured 5star in each one.
+ */
+#[derive(Debug, Clone)]
+struct Statistics {
+    pulls_to_first_s5: Vec<u32>,
+    pulls_to_first_featured: Vec<u32>,
+}
+
+impl Statistics {
+    /* Run `trials` independent sessions of `wish` from `state`, each capped at `max_pulls` pulls
+     * (a session never ends on its own). A trial that lands no 5star, or no featured 5star,
+     * within the cap adds no sample for that event.
+     */
+    fn simulate<C: PityCurve, R: Rng>(
+        wish: &FeaturedWish<C>,
+        state: FeaturedState,
+        trials: u32,
+        max_pulls: u32,
+        rng: &mut R,
+    ) -> Self {
+        let mut pulls_to_first_s5 = Vec::with_capacity(trials as usize);
+        let mut pulls_to_first_featured = Vec::with_capacity(trials as usize);
+
+        for _ in 0..trials {
+            let mut s5_at = None;
+            let mut featured_at = None;
+
+            let session = wish.session(state.clone(), &mut *rng);
+            for (i, roll) in session.enumerate().take(max_pulls as usize) {
+                let pull = (i + 1) as u32;
+                if s5_at.is_none()
+                    && matches!(roll.kind, RollKind::FiveStar | RollKind::FiveStarFeatured)
+                {
+                    s5_at = Some(pull);
+                }
+                if roll.kind == RollKind::FiveStarFeatured {
+                    featured_at = Some(pull);
+                    break;
+                }
+            }
+
+            if let Some(pulls) = s5_at {
+                pulls_to_first_s5.push(pulls);
+            }
+            if let Some(pulls) = featured_at {
+                pulls_to_first_featured.push(pulls);
+            }
+        }
+
+        Self {
+            pulls_to_first_s5,
+            pulls_to_first_featured,
+        }
+    }
+
+    fn histogram(samples: &[u32]) -> BTreeMap<u32, u32> {
+        let mut histogram = BTreeMap::new();
+        for &pulls in samples {
+            *histogram.entry(pulls).or_insert(0) += 1;
+        }
+        histogram
+    }
+
+    /* Simulate `trials` sessions and, in the same call, classify `observed_pulls` (e.g. "you got
+     * the featured 5star in 12 pulls") against the true analytic distribution.
+     */
+    fn report<C: PityCurve, R: Rng>(
+        wish: &FeaturedWish<C>,
+        state: FeaturedState,
+        trials: u32,
+        max_pulls: u32,
+        observed_pulls: u32,
+        rng: &mut R,
+    ) -> LuckSummary {
+        let stats = Self::simulate(wish, state.clone(), trials, max_pulls, rng);
+
+        LuckSummary {
+            s5_histogram: Self::histogram(&stats.pulls_to_first_s5),
+            s5_report: LuckReport::from_samples(&stats.pulls_to_first_s5),
+            featured_histogram: Self::histogram(&stats.pulls_to_first_featured),
+            featured_report: LuckReport::from_samples(&stats.pulls_to_first_featured),
+            observed_tier: wish.classify_featured_luck(&state, observed_pulls),
+        }
+    }
+}